@@ -1,4 +1,5 @@
 pub mod clock;
+pub mod instant;
 mod input_stream;
 mod mutex;
 mod sleep;
@@ -1,6 +1,7 @@
 //! This module provides the `InputStream` trait and implementations for different input stream types:
 //! - `MemoryInputStream`: A stream backed by an in-memory buffer.
 //! - `FileInputStream`: A stream that reads from a file on disk.
+//! - `ReaderInputStream`: A stream that adapts any `std::io::Read + Seek` type.
 //!
 //! The `InputStream` trait is abstract and serves as a common interface for reading data in various
 //! formats. It allows custom streams (such as reading from a network, compressed file, etc.) to be
@@ -8,7 +9,9 @@
 //! methods for reading and seeking within the data.
 //!
 //! The `MemoryInputStream` and `FileInputStream` types are concrete implementations that provide
-//! specific functionality for reading data from memory or disk, respectively.
+//! specific functionality for reading data from memory or disk, respectively. `ReaderInputStream`
+//! bridges the trait to any type that already implements `Read` and `Seek`, such as a `flate2`
+//! decoder or an in-memory `Cursor`.
 //!
 //! # Examples
 //!
@@ -49,6 +52,76 @@ use std::{
     ptr, slice,
 };
 
+/// `ReaderInputStream` adapts any type that implements [`Read`] and [`Seek`] into an
+/// [`InputStream`], so SFML loaders can pull data from network buffers, decompressors,
+/// `Cursor`s, or any other standard reader without reimplementing the trait each time.
+pub struct ReaderInputStream<R> {
+    reader: R,
+}
+
+impl<R: Read + Seek> ReaderInputStream<R> {
+    /// Wraps `reader` so it can be used anywhere an [`InputStream`] is expected.
+    pub fn new(reader: R) -> Self {
+        Self { reader }
+    }
+
+    /// Consumes the adapter, returning the wrapped reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read + Seek> InputStream for ReaderInputStream<R> {
+    fn read(&mut self, data: *mut c_void, size: i64) -> i64 {
+        if size < 0 {
+            return -1;
+        }
+
+        let buffer = unsafe { slice::from_raw_parts_mut(data.cast::<u8>(), size as usize) };
+
+        match self.reader.read(buffer) {
+            Ok(bytes_read) => bytes_read as i64,
+            Err(_) => -1,
+        }
+    }
+
+    fn seek(&mut self, position: i64) -> i64 {
+        if position < 0 {
+            return -1;
+        }
+
+        match self.reader.seek(SeekFrom::Start(position as u64)) {
+            Ok(pos) => pos as i64,
+            Err(_) => -1,
+        }
+    }
+
+    fn tell(&mut self) -> i64 {
+        match self.reader.stream_position() {
+            Ok(pos) => pos as i64,
+            Err(_) => -1,
+        }
+    }
+
+    fn size(&mut self) -> i64 {
+        let current_pos = match self.reader.stream_position() {
+            Ok(pos) => pos,
+            Err(_) => return -1,
+        };
+
+        let size = match self.reader.seek(SeekFrom::End(0)) {
+            Ok(size) => size,
+            Err(_) => return -1,
+        };
+
+        if self.reader.seek(SeekFrom::Start(current_pos)).is_err() {
+            return -1;
+        }
+
+        size as i64
+    }
+}
+
 /// A trait that defines a common interface for reading data from various sources.
 ///
 /// Concrete implementations of this trait must provide methods to read data, seek, tell the position,
@@ -0,0 +1,87 @@
+use std::{
+    ops::{Add, Sub},
+    sync::OnceLock,
+};
+
+use super::{clock::Clock, time::Time};
+
+/// A point on the monotonic clock that [`Clock`] measures against.
+///
+/// Where [`Time`] represents a duration, `Instant` represents an absolute timestamp,
+/// following the `Instant`/`Duration` split used elsewhere in the ecosystem (see RFC
+/// 1288). Subtracting two `Instant`s yields the signed [`Time`] elapsed between them,
+/// and an `Instant` can be shifted forward or backward by a [`Time`] delta, which makes
+/// it possible to express scheduling deadlines and timeouts as absolute instants
+/// instead of repeatedly restarting a `Clock`.
+///
+/// # Example
+///
+/// ```rust
+/// use rust_sfml::system::instant::Instant;
+///
+/// let start = Instant::now();
+/// let deadline = start + rust_sfml::system::time::Time::seconds(1.0);
+///
+/// assert!(Instant::now() < deadline);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Instant {
+    microseconds: i64,
+}
+
+impl Instant {
+    /// Returns an `Instant` representing "now" on SFML's monotonic clock.
+    pub fn now() -> Self {
+        Self {
+            microseconds: epoch().elapsed_time().as_microseconds(),
+        }
+    }
+
+    /// Returns the [`Time`] elapsed since this `Instant` was taken.
+    pub fn elapsed(&self) -> Time {
+        Self::now() - *self
+    }
+}
+
+impl Sub for Instant {
+    type Output = Time;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Time::microseconds(self.microseconds - rhs.microseconds)
+    }
+}
+
+impl Add<Time> for Instant {
+    type Output = Self;
+
+    fn add(self, rhs: Time) -> Self::Output {
+        Self {
+            microseconds: self.microseconds + rhs.as_microseconds(),
+        }
+    }
+}
+
+impl Sub<Time> for Instant {
+    type Output = Self;
+
+    fn sub(self, rhs: Time) -> Self::Output {
+        Self {
+            microseconds: self.microseconds - rhs.as_microseconds(),
+        }
+    }
+}
+
+/// A process-wide `Clock` used as the monotonic source for [`Instant::now`].
+struct EpochClock(Clock);
+
+// SAFETY: `sfClock_getElapsedTime` only reads the underlying clock, and SFML's clock
+// implementation is documented to be safe to query from multiple threads, so it's fine
+// for the `Clock` to be accessed (`Sync`) or moved (`Send`) across threads even though
+// its raw `sfClock` pointer isn't `Send`/`Sync` by default.
+unsafe impl Send for EpochClock {}
+unsafe impl Sync for EpochClock {}
+
+fn epoch() -> &'static Clock {
+    static EPOCH: OnceLock<EpochClock> = OnceLock::new();
+    &EPOCH.get_or_init(|| EpochClock(Clock::new())).0
+}
@@ -1,11 +1,12 @@
 //! A module providing synchronization primitives, specifically a `Mutex`
 //! for mutual exclusion in a multithreaded environment. This module is
-//! a Rust implementation inspired by SFML's `sf::Mutex` class and its
-//! related helper `sf::Lock` class.
+//! a Rust implementation inspired by SFML's `sf::Mutex` class, mirroring
+//! the ergonomics of `std::sync::Mutex` by tying the protected data to
+//! the lock itself.
 //!
 //! ## Notes on Deadlock and Best Practices
 //!
-//! Be cautious with how you use `Mutex` and `Lock`. A common pitfall is
+//! Be cautious with how you use `Mutex`. A common pitfall is
 //! **deadlock**, where two or more threads are waiting on each other to
 //! release a mutex, causing the program to get stuck. Avoid situations
 //! where a thread locks multiple mutexes in a nested manner unless
@@ -16,64 +17,130 @@
 //! as small as possible to reduce contention between threads and to
 //! avoid performance bottlenecks.
 
-use derive_more::derive::{AsMut, AsRef, Deref, DerefMut};
+use std::{
+    cell::UnsafeCell,
+    ops::{Deref, DerefMut},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
 use sfml_sys::{sfMutex, sfMutex_create, sfMutex_destroy, sfMutex_lock, sfMutex_unlock};
 
-#[derive(Debug, Clone, Deref, DerefMut, AsRef, AsMut)]
-pub struct Mutex {
-    __ptr: *mut sfMutex, // Pointer to the internal mutex implementation
+/// A mutual-exclusion primitive that protects shared data of type `T`, backed by
+/// `sf::Mutex`.
+///
+/// Unlike a bare `sfMutex`, `Mutex<T>` owns the data it protects: the only way to reach
+/// the inner value is through the [`MutexGuard`] returned by [`lock`](Mutex::lock) or
+/// [`try_lock`](Mutex::try_lock), which unlocks the mutex automatically when dropped.
+pub struct Mutex<T> {
+    __ptr: *mut sfMutex,
+    locked: AtomicBool,
+    data: UnsafeCell<T>,
 }
 
-impl Default for Mutex {
-    fn default() -> Self {
+// SAFETY: `Mutex<T>` only ever exposes `&T`/`&mut T` through a `MutexGuard` while the
+// underlying `sfMutex` is held, so it is sound to send and share across threads
+// whenever `T` is `Send`, exactly as `std::sync::Mutex<T>` is.
+unsafe impl<T: Send> Send for Mutex<T> {}
+unsafe impl<T: Send> Sync for Mutex<T> {}
+
+impl<T> Mutex<T> {
+    /// Creates a new `Mutex` protecting `data`.
+    pub fn new(data: T) -> Self {
         Self {
             __ptr: unsafe { sfMutex_create() },
+            locked: AtomicBool::new(false),
+            data: UnsafeCell::new(data),
         }
     }
-}
 
-impl Drop for Mutex {
-    fn drop(&mut self) {
-        unsafe { sfMutex_destroy(self.__ptr) };
-    }
-}
+    /// Locks the mutex, blocking the current thread until it is available, and
+    /// returns a guard granting access to the protected data.
+    ///
+    /// Ownership is decided solely by `locked` below: a thread only ever touches the
+    /// raw `sfMutex` once it has won the compare-and-swap, so `sfMutex_lock`/
+    /// `sfMutex_unlock` always pair up one owner at a time and never race with each
+    /// other (see `try_lock` for why that also makes it safe to call here).
+    pub fn lock(&self) -> MutexGuard<'_, T> {
+        // Spin briefly for short critical sections, then yield the thread instead of
+        // pegging the core for however long the current owner holds the lock.
+        let mut spins = 0u32;
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            if spins < 100 {
+                std::hint::spin_loop();
+                spins += 1;
+            } else {
+                std::thread::yield_now();
+            }
+        }
 
-impl Mutex {
-    // Creates a new Mutex
-    pub fn new() -> Self {
-        Self::default()
+        unsafe { sfMutex_lock(self.__ptr) };
+
+        MutexGuard { mutex: self }
     }
 
-    // Locks the mutex, blocking if the mutex is already locked
-    pub fn lock(&self) {
+    /// Attempts to lock the mutex without blocking, returning `None` if it is already
+    /// locked.
+    ///
+    /// `sf::Mutex` has no non-blocking lock primitive of its own, so exclusivity is
+    /// decided entirely by the compare-and-swap on `locked` above: losing it returns
+    /// `None` immediately, and winning it guarantees `sfMutex_lock` below can't
+    /// block, because `unlock` always releases the raw `sfMutex` *before* clearing
+    /// `locked` — by the time our CAS observes `locked == false`, the previous
+    /// owner's `sfMutex_unlock` has already happened-before it, and no other thread
+    /// can be mid-lock concurrently (the CAS never lets two threads hold `locked` at
+    /// once).
+    pub fn try_lock(&self) -> Option<MutexGuard<'_, T>> {
+        if self
+            .locked
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            return None;
+        }
+
         unsafe { sfMutex_lock(self.__ptr) };
+
+        Some(MutexGuard { mutex: self })
     }
 
-    // Unlocks the mutex
-    pub fn unlock(&self) {
+    fn unlock(&self) {
         unsafe { sfMutex_unlock(self.__ptr) };
+        self.locked.store(false, Ordering::Release);
+    }
+}
+
+impl<T> Drop for Mutex<T> {
+    fn drop(&mut self) {
+        unsafe { sfMutex_destroy(self.__ptr) };
     }
 }
 
-// RAII (Resource Acquisition Is Initialization) wrapper for Mutex to automatically unlock
-// the mutex when it goes out of scope
-#[repr(C)]
-#[derive(Debug, Clone)]
-pub struct Lock<'a> {
-    mutex: &'a Mutex, // Reference to the Mutex being locked
+/// An RAII guard granting access to the data protected by a [`Mutex`].
+///
+/// The mutex is unlocked automatically when the guard is dropped.
+pub struct MutexGuard<'a, T> {
+    mutex: &'a Mutex<T>,
 }
 
-impl<'a> Lock<'a> {
-    // Locks the mutex when the Lock object is created
-    pub fn new(mutex: &'a Mutex) -> Self {
-        mutex.lock();
+impl<T> Deref for MutexGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.mutex.data.get() }
+    }
+}
 
-        Self { mutex }
+impl<T> DerefMut for MutexGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.mutex.data.get() }
     }
 }
 
-impl Drop for Lock<'_> {
-    // Automatically unlocks the mutex when the Lock object goes out of scope
+impl<T> Drop for MutexGuard<'_, T> {
     fn drop(&mut self) {
         self.mutex.unlock();
     }
@@ -1,4 +1,7 @@
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+use std::{
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign},
+    time::Duration,
+};
 
 use derive_more::derive::{AsMut, AsRef, Deref, DerefMut};
 use sfml_sys::{
@@ -77,11 +80,28 @@ impl PartialOrd for Time {
     }
 }
 
+impl std::fmt::Display for Time {
+    /// Renders this `Time` as `M:SS.mmm`, growing to `H:MM:SS.mmm` once the
+    /// duration reaches an hour, the way media player clocks do; the leading
+    /// field is not zero-padded. Negative durations are rendered with a leading `-`.
+    ///
+    /// Use [`Time::fmt_hms`] instead if you always want the hours field present.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (sign, hours, minutes, seconds, millis) = self.split_hms();
+
+        if hours > 0 {
+            write!(f, "{sign}{hours}:{minutes:02}:{seconds:02}.{millis:03}")
+        } else {
+            write!(f, "{sign}{minutes}:{seconds:02}.{millis:03}")
+        }
+    }
+}
+
 impl Sub for Time {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        Self::microseconds(-rhs.as_microseconds())
+        self.saturating_sub(rhs)
     }
 }
 
@@ -96,7 +116,7 @@ impl Add for Time {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        Self::microseconds(self.as_microseconds() + rhs.as_microseconds())
+        self.saturating_add(rhs)
     }
 }
 
@@ -119,7 +139,7 @@ impl Mul<i64> for Time {
     type Output = Time;
 
     fn mul(self, rhs: i64) -> Self::Output {
-        Self::microseconds(self.as_microseconds() * rhs)
+        self.saturating_mul(rhs)
     }
 }
 
@@ -185,7 +205,55 @@ impl RemAssign for Time {
     }
 }
 
+impl From<Time> for Duration {
+    /// Converts a `Time` into a `Duration`, clamping negative times to zero since
+    /// `Duration` cannot represent them.
+    fn from(time: Time) -> Self {
+        let micros = time.as_microseconds();
+
+        if micros <= 0 {
+            Duration::ZERO
+        } else {
+            Duration::from_micros(micros as u64)
+        }
+    }
+}
+
+/// Error returned by [`TryFrom<Duration>`] for [`Time`] when the duration is too large
+/// to be represented as a signed number of microseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromDurationError(());
+
+impl std::fmt::Display for TryFromDurationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("duration is too large to fit in a `Time`")
+    }
+}
+
+impl std::error::Error for TryFromDurationError {}
+
+impl TryFrom<Duration> for Time {
+    type Error = TryFromDurationError;
+
+    /// Converts a `Duration` into a `Time`, failing if the duration's microsecond
+    /// count overflows `i64`.
+    fn try_from(duration: Duration) -> Result<Self, Self::Error> {
+        i64::try_from(duration.as_micros())
+            .map(Self::microseconds)
+            .map_err(|_| TryFromDurationError(()))
+    }
+}
+
 impl Time {
+    /// A `Time` value of zero.
+    pub const ZERO: Self = Self::new(0);
+
+    /// The smallest representable `Time` value.
+    pub const MIN: Self = Self::new(i64::MIN);
+
+    /// The largest representable `Time` value.
+    pub const MAX: Self = Self::new(i64::MAX);
+
     /// Creates a new `Time` instance from a number of microseconds.
     ///
     /// # Parameters
@@ -195,7 +263,7 @@ impl Time {
     /// # Returns
     ///
     /// Returns a `Time` instance representing the specified time.
-    pub fn new(microseconds: i64) -> Self {
+    pub const fn new(microseconds: i64) -> Self {
         Self {
             __inner: sfTime { microseconds },
         }
@@ -290,4 +358,123 @@ impl Time {
     pub fn microseconds(amount: i64) -> Self {
         Self::new(amount)
     }
+
+    /// Converts a `Duration` into a `Time`, saturating at the largest representable
+    /// `Time` if the duration's microsecond count overflows `i64`.
+    ///
+    /// Use [`TryFrom<Duration>`](TryFrom) instead if overflow should be an error rather
+    /// than silently clamped.
+    pub fn saturating_from_duration(duration: Duration) -> Self {
+        match i64::try_from(duration.as_micros()) {
+            Ok(micros) => Self::microseconds(micros),
+            Err(_) => Self::microseconds(i64::MAX),
+        }
+    }
+
+    /// Adds two `Time` values, returning `None` if the result overflows.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.as_microseconds()
+            .checked_add(rhs.as_microseconds())
+            .map(Self::microseconds)
+    }
+
+    /// Subtracts `rhs` from `self`, returning `None` if the result overflows.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.as_microseconds()
+            .checked_sub(rhs.as_microseconds())
+            .map(Self::microseconds)
+    }
+
+    /// Multiplies `self` by `rhs`, returning `None` if the result overflows.
+    pub fn checked_mul(self, rhs: i64) -> Option<Self> {
+        self.as_microseconds()
+            .checked_mul(rhs)
+            .map(Self::microseconds)
+    }
+
+    /// Adds two `Time` values, saturating at [`Time::MIN`]/[`Time::MAX`] on overflow
+    /// instead of silently wrapping.
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self::microseconds(self.as_microseconds().saturating_add(rhs.as_microseconds()))
+    }
+
+    /// Subtracts `rhs` from `self`, saturating at [`Time::MIN`]/[`Time::MAX`] on
+    /// overflow instead of silently wrapping.
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self::microseconds(self.as_microseconds().saturating_sub(rhs.as_microseconds()))
+    }
+
+    /// Multiplies `self` by `rhs`, saturating at [`Time::MIN`]/[`Time::MAX`] on
+    /// overflow instead of silently wrapping.
+    pub fn saturating_mul(self, rhs: i64) -> Self {
+        Self::microseconds(self.as_microseconds().saturating_mul(rhs))
+    }
+
+    /// Formats this `Time` as `HH:MM:SS.mmm`, always including the hours field
+    /// regardless of magnitude. See the [`Display`](std::fmt::Display) impl for a
+    /// version that omits the hours field for durations under an hour.
+    pub fn fmt_hms(&self) -> String {
+        let (sign, hours, minutes, seconds, millis) = self.split_hms();
+        format!("{sign}{hours:02}:{minutes:02}:{seconds:02}.{millis:03}")
+    }
+
+    /// Splits this `Time` into a sign, hours, minutes, seconds, and milliseconds,
+    /// for use by the `Display` and `fmt_hms` formatters.
+    fn split_hms(self) -> (&'static str, i64, i64, i64, i64) {
+        let micros = self.as_microseconds();
+        let sign = if micros < 0 { "-" } else { "" };
+        let micros = micros.unsigned_abs();
+
+        let millis = (micros / 1_000 % 1_000) as i64;
+        let total_seconds = micros / 1_000_000;
+        let hours = (total_seconds / 3600) as i64;
+        let minutes = (total_seconds / 60 % 60) as i64;
+        let seconds = (total_seconds % 60) as i64;
+
+        (sign, hours, minutes, seconds, millis)
+    }
+
+    /// Constructs a `Time` from a POSIX `timespec`-style `(seconds, nanoseconds)` pair.
+    ///
+    /// `nanoseconds` is rounded to the nearest microsecond.
+    pub fn from_timespec(sec: i64, nsec: i64) -> Self {
+        Self::microseconds(sec.saturating_mul(1_000_000).saturating_add(round_div(nsec, 1_000)))
+    }
+
+    /// Constructs a `Time` from a POSIX `timeval`-style `(seconds, microseconds)` pair.
+    pub fn from_timeval(sec: i64, usec: i64) -> Self {
+        Self::microseconds(sec.saturating_mul(1_000_000).saturating_add(usec))
+    }
+
+    /// Converts this `Time` to a POSIX `timespec`-style `(seconds, nanoseconds)` pair.
+    ///
+    /// As required by `timespec`, the nanoseconds field is always in `0..1_000_000_000`;
+    /// for a negative `Time` this means `seconds` is rounded down while `nanoseconds`
+    /// holds the non-negative remainder (e.g. -1.5s becomes `(-2, 500_000_000)`).
+    pub fn to_timespec(self) -> (i64, i64) {
+        let micros = self.as_microseconds();
+        (micros.div_euclid(1_000_000), micros.rem_euclid(1_000_000) * 1_000)
+    }
+
+    /// Converts this `Time` to a POSIX `timeval`-style `(seconds, microseconds)` pair.
+    ///
+    /// As required by `timeval`, the microseconds field is always in `0..1_000_000`;
+    /// for a negative `Time` this means `seconds` is rounded down while `microseconds`
+    /// holds the non-negative remainder (e.g. -1.5s becomes `(-2, 500_000)`).
+    pub fn to_timeval(self) -> (i64, i64) {
+        let micros = self.as_microseconds();
+        (micros.div_euclid(1_000_000), micros.rem_euclid(1_000_000))
+    }
+}
+
+/// Divides `numerator` by `denominator`, rounding to the nearest integer (ties away
+/// from zero) instead of truncating.
+fn round_div(numerator: i64, denominator: i64) -> i64 {
+    let half = denominator / 2;
+
+    if numerator >= 0 {
+        (numerator + half) / denominator
+    } else {
+        -((-numerator + half) / denominator)
+    }
 }
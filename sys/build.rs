@@ -18,25 +18,132 @@ use zip::ZipArchive;
 #[tokio::main]
 async fn main() {
     println!("cargo:rerun-if-changed=CSFML");
+    println!("cargo:rerun-if-env-changed=CSFML_HOME");
 
     // Read feature flags
-    let feat_audio = env::var("CARGO_FEATURE_AUDIO").is_ok();
-    let feat_window = env::var("CARGO_FEATURE_WINDOW").is_ok();
-    let feat_graphics = env::var("CARGO_FEATURE_GRAPHICS").is_ok();
+    let subsystems = Subsystems {
+        audio: env::var("CARGO_FEATURE_AUDIO").is_ok(),
+        window: env::var("CARGO_FEATURE_WINDOW").is_ok(),
+        graphics: env::var("CARGO_FEATURE_GRAPHICS").is_ok(),
+    };
+    let feat_pkg_config = env::var("CARGO_FEATURE_PKG_CONFIG").is_ok();
 
-    // If the CSFML directory doesn't exist, download and extract it
-    if !Path::new("CSFML").exists() {
-        let url = get_cfml_url();
-        let _ = download_and_extract_csfml(url).await.unwrap();
-    }
+    // Prefer an already-installed system CSFML (the common case on Linux, where SFML
+    // ships no precompiled archives) over downloading one.
+    let system_csfml = find_system_csfml(feat_pkg_config, subsystems);
+
+    let extra_include_dir = if let Some(csfml) = &system_csfml {
+        println!("cargo:rustc-link-search={}", csfml.lib_dir.display());
+        Some(csfml.include_dir.clone())
+    } else {
+        // If the CSFML directory doesn't exist, download and extract it
+        if !Path::new("CSFML").exists() {
+            let url = get_cfml_url();
+            let _ = download_and_extract_csfml(url).await.unwrap();
+        }
 
-    // Set the library search path
-    println!("cargo:rustc-link-search=/sys/CSFML/lib");
+        // Set the library search path
+        println!("cargo:rustc-link-search=/sys/CSFML/lib");
+        None
+    };
 
     // Generate wrapper header and bindings
     let bindings_header = "wrapper.h";
-    generate_wrapper(bindings_header, feat_audio, feat_window, feat_graphics);
-    generate_bindings(bindings_header);
+    generate_wrapper(bindings_header, subsystems);
+    generate_bindings(bindings_header, extra_include_dir.as_deref());
+}
+
+/// A CSFML install discovered on the host system, as opposed to one downloaded by
+/// [`download_and_extract_csfml`].
+struct SystemCsfml {
+    include_dir: PathBuf,
+    lib_dir: PathBuf,
+}
+
+/// Which optional CSFML subsystems are enabled via cargo features.
+#[derive(Clone, Copy)]
+struct Subsystems {
+    audio: bool,
+    window: bool,
+    graphics: bool,
+}
+
+/// Looks for an already-installed CSFML, first via `pkg-config` (if the `pkg-config`
+/// feature is enabled) and then by probing standard install prefixes. Returns `None`
+/// if neither approach finds one, in which case the caller falls back to downloading a
+/// precompiled archive.
+fn find_system_csfml(use_pkg_config: bool, subsystems: Subsystems) -> Option<SystemCsfml> {
+    if use_pkg_config {
+        if let Some(csfml) = find_via_pkg_config(subsystems) {
+            return Some(csfml);
+        }
+    }
+
+    find_via_standard_prefixes()
+}
+
+/// Queries `pkg-config` for the CSFML subsystems selected by the enabled features.
+fn find_via_pkg_config(subsystems: Subsystems) -> Option<SystemCsfml> {
+    let mut libs = vec!["csfml-system"];
+    if subsystems.audio {
+        libs.push("csfml-audio");
+    }
+    if subsystems.window {
+        libs.push("csfml-window");
+    }
+    if subsystems.graphics {
+        libs.push("csfml-graphics");
+    }
+
+    let mut include_dir = None;
+    let mut lib_dir = None;
+
+    for lib in libs {
+        let library = pkg_config::probe_library(lib).ok()?;
+        include_dir = include_dir.or_else(|| library.include_paths.first().cloned());
+        lib_dir = lib_dir.or_else(|| library.link_paths.first().cloned());
+    }
+
+    Some(SystemCsfml {
+        include_dir: include_dir?,
+        lib_dir: lib_dir?,
+    })
+}
+
+/// Looks for `include/SFML` and `libcsfml-*.so` under `/usr`, `/usr/local`, and
+/// `CSFML_HOME` (in that order).
+fn find_via_standard_prefixes() -> Option<SystemCsfml> {
+    let mut prefixes = vec![PathBuf::from("/usr"), PathBuf::from("/usr/local")];
+    if let Ok(home) = env::var("CSFML_HOME") {
+        prefixes.push(PathBuf::from(home));
+    }
+
+    prefixes.into_iter().find_map(|prefix| {
+        let include_dir = prefix.join("include");
+        let lib_dir = prefix.join("lib");
+
+        if include_dir.join("SFML").is_dir() && has_csfml_libs(&lib_dir) {
+            Some(SystemCsfml {
+                include_dir,
+                lib_dir,
+            })
+        } else {
+            None
+        }
+    })
+}
+
+/// Returns `true` if `lib_dir` contains at least one `libcsfml-*.so`.
+fn has_csfml_libs(lib_dir: &Path) -> bool {
+    let Ok(entries) = std::fs::read_dir(lib_dir) else {
+        return false;
+    };
+
+    entries.filter_map(std::result::Result::ok).any(|entry| {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        name.starts_with("libcsfml-") && name.ends_with(".so")
+    })
 }
 
 /// Downloads and extracts the CSFML archive (ZIP or tar.gz) based on the platform.
@@ -152,29 +259,24 @@ fn get_cfml_url() -> &'static str {
 }
 
 /// Generates the wrapper header file based on the selected features.
-fn generate_wrapper(
-    bindings_header: &str,
-    feat_audio: bool,
-    feat_window: bool,
-    feat_graphics: bool,
-) {
+fn generate_wrapper(bindings_header: &str, subsystems: Subsystems) {
     let mut file = File::create(bindings_header).unwrap();
     let mut headers = Vec::new();
 
     headers.push("SFML/System.h");
     link_sfml_subsystem("system");
 
-    if feat_audio {
+    if subsystems.audio {
         headers.push("SFML/Audio.h");
         link_sfml_subsystem("audio");
     }
 
-    if feat_window {
+    if subsystems.window {
         headers.push("SFML/Window.h");
         link_sfml_subsystem("window");
     }
 
-    if feat_graphics {
+    if subsystems.graphics {
         headers.push("SFML/Graphics.h");
         link_sfml_subsystem("graphics");
     }
@@ -185,8 +287,18 @@ fn generate_wrapper(
 }
 
 /// Generates the bindings using the specified wrapper header.
-fn generate_bindings(binding_header: &str) {
-    let bindings = bindgen::Builder::default()
+///
+/// `extra_include_dir` is passed when a system CSFML install was found, so that
+/// bindgen resolves `SFML/*.h` headers from there instead of the bundled `./CSFML`.
+fn generate_bindings(binding_header: &str, extra_include_dir: Option<&Path>) {
+    let mut builder = bindgen::Builder::default();
+
+    // clang searches `-I` directories in order, so the system one must come first.
+    if let Some(include_dir) = extra_include_dir {
+        builder = builder.clang_arg(format!("-I{}", include_dir.display()));
+    }
+
+    let bindings = builder
         .clang_arg("-I./CSFML/include")
         .header(binding_header)
         .parse_callbacks(Box::new(CargoCallbacks::new()))
@@ -0,0 +1,5 @@
+//! Raw FFI bindings to CSFML, generated at build time by `build.rs` via `bindgen`.
+#![no_std]
+#![allow(non_camel_case_types, non_snake_case, non_upper_case_globals)]
+
+include!(concat!(env!("OUT_DIR"), "/bindings.rs"));